@@ -3,31 +3,66 @@ use crate::number::PreciseNumber;
 use crate::numberparse::ParseNumberError;
 use bigdecimal::BigDecimal;
 use num_bigint::BigInt;
+use num_traits::pow::pow as num_pow;
+use num_traits::{Signed, Zero};
+use std::cmp::Ordering;
 
-pub fn parse_hexadecimal_float(s: &str) -> Result<PreciseNumber, ParseNumberError> {
-    let (value, scale) = parse_float(s).map(|x| float_to_scaled_integer(x, None))?;
-    let num = BigInt::from(value);
-    let num = BigDecimal::from_bigint(num, -scale);
-    let fractional_digits = if scale < 0 { -scale as usize } else { 0 };
-    Ok(PreciseNumber::new(
-        ExtendedBigDecimal::BigDecimal(num),
-        0,
-        fractional_digits,
-    ))
+/// The exact sign, mantissa and binary exponent of a parsed hexadecimal or
+/// binary float, i.e. the value `sign * mantissa * 2^binary_exponent`.
+///
+/// Downstream utilities (`seq`, `printf`, `numfmt`) frequently need the raw
+/// binary significand and exponent rather than a lossy scaled-integer pair,
+/// and keeping the mantissa in [`BigInt`] form avoids the `u64` overflow
+/// that silently truncated integer parts longer than 16 hex digits to `0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RadixFloat {
+    pub sign: i8,
+    pub mantissa: BigInt,
+    pub binary_exponent: i64,
 }
 
-fn parse_float(s: &str) -> Result<f64, ParseNumberError> {
+impl RadixFloat {
+    /// Convert into the exact [`PreciseNumber`] this float represents.
+    pub fn into_precise_number(self) -> PreciseNumber {
+        let (num, fractional_digits) =
+            exact_value_from_mantissa(self.sign, self.mantissa, self.binary_exponent);
+        PreciseNumber::new(ExtendedBigDecimal::BigDecimal(num), 0, fractional_digits)
+    }
+}
+
+/// Parse a hexadecimal float (e.g. `0x1.8p2`) into a [`RadixFloat`].
+///
+/// Hex floats are always exactly representable in decimal, so the digits
+/// are accumulated into a [`BigInt`] mantissa and paired with the binary
+/// exponent, rather than routed through an `f64` intermediate, which would
+/// lose precision and overflow for large exponents. Call
+/// [`RadixFloat::into_precise_number`] to get the exact decimal value.
+pub fn parse_hexadecimal_float(s: &str) -> Result<RadixFloat, ParseNumberError> {
+    let (sign, mantissa, binary_exponent) = parse_hex_exact(s)?;
+    Ok(RadixFloat {
+        sign,
+        mantissa,
+        binary_exponent,
+    })
+}
+
+/// Parse the sign, digits and binary exponent of a hexadecimal float.
+///
+/// Returns the sign, the mantissa formed by concatenating the integer and
+/// fractional hex digits (read as a single integer), and `e` such that the
+/// value is `sign * mantissa * 2^e`.
+fn parse_hex_exact(s: &str) -> Result<(i8, BigInt, i64), ParseNumberError> {
     let mut s = s.trim();
 
     // Detect a sign
-    let sign = if s.starts_with('-') {
+    let sign: i8 = if s.starts_with('-') {
         s = &s[1..];
-        -1.0
+        -1
     } else if s.starts_with('+') {
         s = &s[1..];
-        1.0
+        1
     } else {
-        1.0
+        1
     };
 
     // Is HEX?
@@ -38,17 +73,20 @@ fn parse_float(s: &str) -> Result<f64, ParseNumberError> {
     }
 
     // Read an integer part (if presented)
-    let length = s.chars().take_while(|c| c.is_ascii_hexdigit()).count();
-    let integer = u64::from_str_radix(&s[..length], 16).unwrap_or(0);
-    s = &s[length..];
+    let int_length = s.chars().take_while(|c| c.is_ascii_hexdigit()).count();
+    let int_digits = &s[..int_length];
+    s = &s[int_length..];
 
     // Read a fractional part (if presented)
-    let fractional = if s.starts_with('.') {
+    let frac_digits = if s.starts_with('.') {
         s = &s[1..];
-        let length = s.chars().take_while(|c| c.is_ascii_hexdigit()).count();
-        let value = parse_fractional_part(&s[..length])?;
-        s = &s[length..];
-        Some(value)
+        let frac_length = s.chars().take_while(|c| c.is_ascii_hexdigit()).count();
+        if frac_length == 0 {
+            return Err(ParseNumberError::Float);
+        }
+        let digits = &s[..frac_length];
+        s = &s[frac_length..];
+        Some(digits)
     } else {
         None
     };
@@ -60,7 +98,7 @@ fn parse_float(s: &str) -> Result<f64, ParseNumberError> {
             .chars()
             .take_while(|c| c.is_ascii_digit() || *c == '-' || *c == '+')
             .count();
-        let value = s[..length].parse().map_err(|_| ParseNumberError::Float)?;
+        let value: i64 = s[..length].parse().map_err(|_| ParseNumberError::Float)?;
         s = &s[length..];
         Some(value)
     } else {
@@ -71,7 +109,7 @@ fn parse_float(s: &str) -> Result<f64, ParseNumberError> {
     // - Both Fractions & Power values can't be none in the same time
     // - string should be consumed. Otherwise, it's possible to have garbage symbols after the HEX
     // float
-    if fractional.is_none() && power.is_none() {
+    if frac_digits.is_none() && power.is_none() {
         return Err(ParseNumberError::Float);
     }
 
@@ -79,70 +117,325 @@ fn parse_float(s: &str) -> Result<f64, ParseNumberError> {
         return Err(ParseNumberError::Float);
     }
 
-    // Build the result
-    let total =
-        sign * (integer as f64 + fractional.unwrap_or(0.0)) * (2.0_f64).powi(power.unwrap_or(0));
-    Ok(total)
+    // Accumulate all digits (integer and fractional) into a single mantissa,
+    // reading them as one big hexadecimal integer.
+    let frac_length = frac_digits.map_or(0, str::len);
+    let mut digits = String::with_capacity(int_digits.len() + frac_length);
+    digits.push_str(int_digits);
+    if let Some(d) = frac_digits {
+        digits.push_str(d);
+    }
+    let mantissa = if digits.is_empty() {
+        BigInt::from(0)
+    } else {
+        BigInt::parse_bytes(digits.as_bytes(), 16).ok_or(ParseNumberError::Float)?
+    };
+
+    // value = mantissa * 16^(-frac_length) * 2^power = mantissa * 2^(power - 4 * frac_length)
+    let binary_exponent = power.unwrap_or(0) - 4 * frac_length as i64;
+    Ok((sign, mantissa, binary_exponent))
+}
+
+/// Parse a binary float (e.g. `0b1010.101p3`) into a [`RadixFloat`].
+///
+/// Binary floats are exactly representable in decimal for the same reason
+/// hexadecimal floats are, so this mirrors [`parse_hexadecimal_float`] and
+/// shares its exact `BigInt` mantissa/exponent representation.
+pub fn parse_binary_float(s: &str) -> Result<RadixFloat, ParseNumberError> {
+    let (sign, mantissa, binary_exponent) = parse_binary_exact(s)?;
+    Ok(RadixFloat {
+        sign,
+        mantissa,
+        binary_exponent,
+    })
 }
 
-fn parse_fractional_part(s: &str) -> Result<f64, ParseNumberError> {
-    if s.is_empty() {
+/// Parse the sign, digits and binary exponent of a binary float.
+///
+/// Mirrors [`parse_hex_exact`], but with a `0b`/`0B` prefix and base-2
+/// digits, so each digit after the point contributes `bit * 2^(-k)` rather
+/// than `digit * 16^(-k)`.
+fn parse_binary_exact(s: &str) -> Result<(i8, BigInt, i64), ParseNumberError> {
+    let mut s = s.trim();
+
+    // Detect a sign
+    let sign: i8 = if s.starts_with('-') {
+        s = &s[1..];
+        -1
+    } else if s.starts_with('+') {
+        s = &s[1..];
+        1
+    } else {
+        1
+    };
+
+    // Is BINARY?
+    if s.starts_with("0b") || s.starts_with("0B") {
+        s = &s[2..];
+    } else {
+        return Err(ParseNumberError::Float);
+    }
+
+    // Read an integer part (if presented)
+    let is_binary_digit = |c: &char| *c == '0' || *c == '1';
+    let int_length = s.chars().take_while(is_binary_digit).count();
+    let int_digits = &s[..int_length];
+    s = &s[int_length..];
+
+    // Read a fractional part (if presented)
+    let frac_digits = if s.starts_with('.') {
+        s = &s[1..];
+        let frac_length = s.chars().take_while(is_binary_digit).count();
+        if frac_length == 0 {
+            return Err(ParseNumberError::Float);
+        }
+        let digits = &s[..frac_length];
+        s = &s[frac_length..];
+        Some(digits)
+    } else {
+        None
+    };
+
+    // Read a power (if presented)
+    let power = if s.starts_with('p') || s.starts_with('P') {
+        s = &s[1..];
+        let length = s
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == '-' || *c == '+')
+            .count();
+        let value: i64 = s[..length].parse().map_err(|_| ParseNumberError::Float)?;
+        s = &s[length..];
+        Some(value)
+    } else {
+        None
+    };
+
+    // Post checks:
+    // - Both Fractions & Power values can't be none in the same time
+    // - string should be consumed. Otherwise, it's possible to have garbage symbols after the
+    // binary float
+    if frac_digits.is_none() && power.is_none() {
         return Err(ParseNumberError::Float);
     }
 
-    let mut multiplier = 1.0 / 16.0;
-    let mut total = 0.0;
-    for c in s.chars() {
-        let digit = c
-            .to_digit(16)
-            .map(|x| x as u8)
-            .ok_or(ParseNumberError::Float)?;
-        total += (digit as f64) * multiplier;
-        multiplier /= 16.0;
+    if !s.is_empty() {
+        return Err(ParseNumberError::Float);
+    }
+
+    // Accumulate all digits (integer and fractional) into a single mantissa,
+    // reading them as one big binary integer.
+    let frac_length = frac_digits.map_or(0, str::len);
+    let mut digits = String::with_capacity(int_digits.len() + frac_length);
+    digits.push_str(int_digits);
+    if let Some(d) = frac_digits {
+        digits.push_str(d);
+    }
+    let mantissa = if digits.is_empty() {
+        BigInt::from(0)
+    } else {
+        BigInt::parse_bytes(digits.as_bytes(), 2).ok_or(ParseNumberError::Float)?
+    };
+
+    // value = mantissa * 2^(-frac_length) * 2^power = mantissa * 2^(power - frac_length)
+    let binary_exponent = power.unwrap_or(0) - frac_length as i64;
+    Ok((sign, mantissa, binary_exponent))
+}
+
+/// Build the exact decimal value of `sign * mantissa * 2^e`.
+///
+/// Returns the [`BigDecimal`] together with the number of fractional
+/// (decimal) digits it took to represent it exactly. When `e` is negative,
+/// `2^e` is rewritten as `5^|e| / 10^|e|`, which keeps the result an exact
+/// integer numerator over a power-of-ten denominator.
+fn exact_value_from_mantissa(sign: i8, mantissa: BigInt, e: i64) -> (BigDecimal, usize) {
+    let mantissa = if sign < 0 { -mantissa } else { mantissa };
+    if e >= 0 {
+        let value = mantissa * num_pow(BigInt::from(2), e as usize);
+        (BigDecimal::from_bigint(value, 0), 0)
+    } else {
+        let scale = (-e) as usize;
+        let value = mantissa * num_pow(BigInt::from(5), scale);
+        (BigDecimal::from_bigint(value, scale as i64), scale)
+    }
+}
+
+/// Render an [`ExtendedBigDecimal`] as a C99 `%a`-style hexadecimal float,
+/// e.g. `0x1.8p+2`, with `precision` hex digits after the point.
+///
+/// Rounding to `precision` hex digits (i.e. `4 * precision` bits) is exact
+/// round-to-nearest-even, computed with [`BigInt`] arithmetic so it is not
+/// subject to `f64` rounding error.
+pub fn format_hexadecimal_float(
+    value: &ExtendedBigDecimal,
+    precision: usize,
+    uppercase: bool,
+) -> String {
+    match value {
+        ExtendedBigDecimal::BigDecimal(bd) => {
+            format_finite_hexadecimal_float(bd, precision, uppercase)
+        }
+        ExtendedBigDecimal::Infinity => format_named(false, "inf", uppercase),
+        ExtendedBigDecimal::MinusInfinity => format_named(true, "inf", uppercase),
+        ExtendedBigDecimal::Nan => format_named(false, "nan", uppercase),
+        ExtendedBigDecimal::MinusNan => format_named(true, "nan", uppercase),
+        ExtendedBigDecimal::MinusZero => format_zero(true, precision, uppercase),
     }
-    Ok(total)
 }
 
-fn float_to_scaled_integer(input: f64, precision: Option<f64>) -> (i64, i64) {
-    let mut scaled_value = input;
-    let mut scale = 0;
-    let mut multiplier = 10.0;
-    let precision = precision.unwrap_or(0.000001);
+fn format_named(negative: bool, name: &str, uppercase: bool) -> String {
+    let name = if uppercase {
+        name.to_ascii_uppercase()
+    } else {
+        name.to_string()
+    };
+    if negative {
+        format!("-{name}")
+    } else {
+        name
+    }
+}
+
+fn format_zero(negative: bool, precision: usize, uppercase: bool) -> String {
+    let prefix = if uppercase { "0X0" } else { "0x0" };
+    let exp = if uppercase { "P+0" } else { "p+0" };
+    let sign = if negative { "-" } else { "" };
+    if precision > 0 {
+        format!("{sign}{prefix}.{}{exp}", "0".repeat(precision))
+    } else {
+        format!("{sign}{prefix}{exp}")
+    }
+}
+
+/// Normalize `digits * 10^-scale` to `1.<bits> * 2^e` and round the bits to
+/// `precision` hex digits (`4 * precision` bits).
+fn format_finite_hexadecimal_float(bd: &BigDecimal, precision: usize, uppercase: bool) -> String {
+    let (digits, scale) = bd.as_bigint_and_exponent();
+    if digits.is_zero() {
+        return format_zero(false, precision, uppercase);
+    }
+
+    let negative = digits.is_negative();
+    let abs_digits = digits.abs();
+    let (n, d) = if scale >= 0 {
+        (abs_digits, num_pow(BigInt::from(10), scale as usize))
+    } else {
+        (
+            abs_digits * num_pow(BigInt::from(10), (-scale) as usize),
+            BigInt::from(1),
+        )
+    };
+
+    // Find `e` such that `2^e <= n/d < 2^(e+1)`.
+    let cmp_shifted = |e: i64| -> Ordering {
+        if e >= 0 {
+            n.cmp(&(&d << e as u32))
+        } else {
+            (&n << (-e) as u32).cmp(&d)
+        }
+    };
+    let mut e = n.bits() as i64 - d.bits() as i64;
     loop {
-        let rounded_value = scaled_value.round();
-        if f64::abs(rounded_value - scaled_value) <= precision {
-            return (rounded_value as i64, -scale);
+        if cmp_shifted(e) == Ordering::Less {
+            e -= 1;
+        } else if cmp_shifted(e + 1) != Ordering::Less {
+            e += 1;
+        } else {
+            break;
         }
-        scale += 1;
-        // 'scaled_value *= 10.0' is efficient but less precise, due to accumulating rounding errors over iterations.
-        // 'scaled_value = input * 10.0_f64.powi(scale)' is more precise, but it's less efficient due to recalculating the power each time.
-        // 'scaled_value = input * multiplier' - seems like a good balance between calculation errors and efficiency.
-        scaled_value = input * multiplier;
-        multiplier *= 10.0;
     }
+
+    // Rescale so the normalized value is exactly `r / scaled_d`, with
+    // `1 <= r / scaled_d < 2`.
+    let shift_n = if e < 0 { (-e) as u32 } else { 0 };
+    let shift_d = if e > 0 { e as u32 } else { 0 };
+    let scaled_d = d << shift_d;
+    let mut remainder = (n << shift_n) - &scaled_d;
+
+    let frac_bits = 4 * precision;
+    let mut bits = Vec::with_capacity(frac_bits);
+    for _ in 0..frac_bits {
+        remainder = remainder << 1u32;
+        if remainder >= scaled_d {
+            bits.push(true);
+            remainder = remainder - &scaled_d;
+        } else {
+            bits.push(false);
+        }
+    }
+
+    // Round to nearest, ties to even, using the exact remaining fraction.
+    remainder = remainder << 1u32;
+    let round_bit = remainder >= scaled_d;
+    if round_bit {
+        remainder = remainder - &scaled_d;
+    }
+    let sticky = !remainder.is_zero();
+    let last_kept_bit_odd = bits.last().copied().unwrap_or(true);
+    if round_bit && (sticky || last_kept_bit_odd) {
+        let mut i = bits.len();
+        loop {
+            if i == 0 {
+                // All bits were 1: the mantissa rolls over from 1.111...1 to 10.000...0.
+                e += 1;
+                bits.iter_mut().for_each(|b| *b = false);
+                break;
+            }
+            i -= 1;
+            if bits[i] {
+                bits[i] = false;
+            } else {
+                bits[i] = true;
+                break;
+            }
+        }
+    }
+
+    let hex_digits: String = bits
+        .chunks(4)
+        .map(|chunk| {
+            let value = chunk.iter().fold(0u8, |acc, &b| (acc << 1) | u8::from(b));
+            let c = std::char::from_digit(u32::from(value), 16).unwrap();
+            if uppercase {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    let sign = if negative { "-" } else { "" };
+    let mantissa = if precision > 0 {
+        format!("{}1.{hex_digits}", if uppercase { "0X" } else { "0x" })
+    } else {
+        format!("{}1", if uppercase { "0X" } else { "0x" })
+    };
+    let exp_char = if uppercase { 'P' } else { 'p' };
+    let exp_sign = if e >= 0 { "+" } else { "-" };
+    format!("{sign}{mantissa}{exp_char}{exp_sign}{}", e.abs())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{float_to_scaled_integer, parse_float, parse_hexadecimal_float};
+    use super::{format_hexadecimal_float, parse_binary_float, parse_hexadecimal_float};
     use crate::ExtendedBigDecimal;
     use num_traits::ToPrimitive;
+
     #[test]
     #[allow(clippy::cognitive_complexity)]
-    fn test_parse_float_from_invalid_values() {
+    fn test_parse_hexadecimal_float_from_invalid_values() {
         let samples = vec![
             "1", "1p", "0x1", "0x1.", "0x1p", "0x1p+", "-0xx1p1", "0x1.k", "0x1", "-0x1pa",
             "0x1.1pk", "0x1.8p2z", "0x1p3.2",
         ];
 
         for s in samples {
-            assert_eq!(parse_float(s).is_err(), true);
+            assert_eq!(parse_hexadecimal_float(s).is_err(), true);
         }
     }
 
     #[test]
     #[allow(clippy::cognitive_complexity)]
-    fn test_parse_float_from_valid_values() {
+    fn test_parse_precise_number_from_valid_values() {
         let samples = vec![
             ("0x1p1", 2.0),
             ("+0x1p1", 2.0),
@@ -160,34 +453,108 @@ mod tests {
             ("0x0.0p0", 0.0),
         ];
 
-        for (sample, control_value) in samples {
-            let value = parse_float(sample).unwrap();
-            assert_eq!(value, control_value);
+        for (s, v) in samples {
+            match parse_hexadecimal_float(s)
+                .unwrap()
+                .into_precise_number()
+                .number
+            {
+                ExtendedBigDecimal::BigDecimal(bd) => assert_eq!(bd.to_f64().unwrap(), v),
+                _ => unreachable!(),
+            }
         }
     }
 
+    #[test]
+    fn test_parse_hexadecimal_float_exact_mantissa() {
+        // The fractional part has more bits than an f64 mantissa can hold,
+        // so this value could not survive an f64 round-trip exactly.
+        use bigdecimal::BigDecimal;
+        use num_bigint::BigInt;
+        use num_traits::pow::pow;
+
+        let value = match parse_hexadecimal_float("0x1.0000000000000001p0")
+            .unwrap()
+            .into_precise_number()
+            .number
+        {
+            ExtendedBigDecimal::BigDecimal(bd) => bd,
+            _ => unreachable!(),
+        };
+
+        let mantissa = BigInt::parse_bytes(b"10000000000000001", 16).unwrap();
+        let expected = BigDecimal::from_bigint(mantissa * pow(BigInt::from(5), 64), 64);
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn test_parse_hexadecimal_float_large_exponent_does_not_overflow() {
+        // `(2.0_f64).powi(2000)` would overflow to infinity; the exact path must not.
+        use bigdecimal::BigDecimal;
+        use num_bigint::BigInt;
+
+        let value = match parse_hexadecimal_float("0x1p2000")
+            .unwrap()
+            .into_precise_number()
+            .number
+        {
+            ExtendedBigDecimal::BigDecimal(bd) => bd,
+            _ => unreachable!(),
+        };
+
+        let expected = BigDecimal::from_bigint(BigInt::from(1) << 2000u32, 0);
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn test_parse_hexadecimal_float_long_integer_part_is_exact() {
+        // 17 hex digits is more than `u64::from_str_radix` can hold; the old
+        // `parse_float` silently fell back to treating the integer part as
+        // `0` here. The `BigInt` mantissa must not lose any digits.
+        use num_bigint::BigInt;
+
+        let radix_float = parse_hexadecimal_float("0x123456789abcdef01p0").unwrap();
+        let expected = BigInt::parse_bytes(b"123456789abcdef01", 16).unwrap();
+        assert_eq!(radix_float.mantissa, expected);
+        assert_eq!(radix_float.sign, 1);
+        assert_eq!(radix_float.binary_exponent, 0);
+    }
+
     #[test]
     #[allow(clippy::cognitive_complexity)]
-    fn test_parse_precise_number_from_valid_values() {
+    fn test_parse_binary_float_from_invalid_values() {
         let samples = vec![
-            ("0x1p1", 2.0),
-            ("+0x1p1", 2.0),
-            ("-0x1p1", -2.0),
-            ("0x1p-1", 0.5),
-            ("0x1.8", 1.5),
-            ("-0x1.8", -1.5),
-            ("0x1.8p2", 6.0),
-            ("0x1.8p+2", 6.0),
-            ("0x1.8p-2", 0.375),
-            ("0x.8", 0.5),
-            ("0x10p0", 16.0),
-            ("0x0.0", 0.0),
-            ("0x0p0", 0.0),
-            ("0x0.0p0", 0.0),
+            "1", "1p", "0b1", "0b1.", "0b1p", "0b1p+", "-0bb1p1", "0b1.2", "0b1", "-0b1pa",
+            "0b1.1pk", "0b1.1p2z", "0b1p3.2",
+        ];
+
+        for s in samples {
+            assert_eq!(parse_binary_float(s).is_err(), true);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::cognitive_complexity)]
+    fn test_parse_binary_float_from_valid_values() {
+        let samples = vec![
+            ("0b1p1", 2.0),
+            ("+0b1p1", 2.0),
+            ("-0b1p1", -2.0),
+            ("0b1p-1", 0.5),
+            ("0b1.1", 1.5),
+            ("-0b1.1", -1.5),
+            ("0b1.1p2", 6.0),
+            ("0b1.1p+2", 6.0),
+            ("0b1.1p-2", 0.375),
+            ("0b.1", 0.5),
+            ("0b10000p0", 16.0),
+            ("0b0.0", 0.0),
+            ("0b0p0", 0.0),
+            ("0b0.0p0", 0.0),
         ];
 
         for (s, v) in samples {
-            match parse_hexadecimal_float(s).unwrap().number {
+            match parse_binary_float(s).unwrap().into_precise_number().number {
                 ExtendedBigDecimal::BigDecimal(bd) => assert_eq!(bd.to_f64().unwrap(), v),
                 _ => unreachable!(),
             }
@@ -195,34 +562,113 @@ mod tests {
     }
 
     #[test]
-    #[allow(clippy::cognitive_complexity)]
-    fn test_float_to_scaled_integers() {
+    fn test_parse_binary_float_exact_mantissa() {
+        // 66 fractional bits: more than an f64 mantissa can hold exactly.
+        use bigdecimal::BigDecimal;
+        use num_bigint::BigInt;
+        use num_traits::pow::pow;
+
+        let value = match parse_binary_float(
+            "0b1.000000000000000000000000000000000000000000000000000000000000000001p0",
+        )
+        .unwrap()
+        .into_precise_number()
+        .number
+        {
+            ExtendedBigDecimal::BigDecimal(bd) => bd,
+            _ => unreachable!(),
+        };
+
+        let mantissa = (BigInt::from(1) << 66u32) + 1;
+        let expected = BigDecimal::from_bigint(mantissa * pow(BigInt::from(5), 66), 66);
+        assert_eq!(value, expected);
+    }
+
+    fn parsed(s: &str) -> ExtendedBigDecimal {
+        parse_hexadecimal_float(s)
+            .unwrap()
+            .into_precise_number()
+            .number
+    }
+
+    #[test]
+    fn test_format_hexadecimal_float_round_trip() {
         let samples = [
-            (0.0, (0, 0)),
-            (0.5, (5, -1)),
-            (1.5, (15, -1)),
-            (-0.5, (-5, -1)),
-            (-1.5, (-15, -1)),
-            (0.375, (375, -3)),
-            (1.375, (1375, -3)),
-            (1.375, (1375, -3)),
-            (1.372, (1372, -3)),
-            (1.378, (1378, -3)),
-            (1.0, (1, 0)),
-            (10.0, (10, 0)),
-            (123.12345678, (12312345678, -8)),
-            (-123.12345678, (-12312345678, -8)),
-            (-334.22923, (-33422923, -5)),
-            (1000.00001, (100000001, -5)),
-            (4334.123456788, (4334123456788, -9)),
-            (123456789.123456789, (1234567891234568, -7)), // truncated value
-            (-123456789.123456789, (-1234567891234568, -7)), // truncated value
+            ("0x1.8p2", 1, "0x1.8p+2"),
+            ("-0x1.8p2", 1, "-0x1.8p+2"),
+            ("0x1p0", 0, "0x1p+0"),
+            ("0x1p-3", 0, "0x1p-3"),
         ];
 
-        for (input, (control_value, control_scale)) in samples {
-            let (value, scale) = float_to_scaled_integer(input, None);
-            assert_eq!(value, control_value);
-            assert_eq!(scale, control_scale);
+        for (input, precision, expected) in samples {
+            let value = parsed(input);
+            assert_eq!(format_hexadecimal_float(&value, precision, false), expected);
         }
     }
+
+    #[test]
+    fn test_format_hexadecimal_float_uppercase() {
+        let value = parsed("0x1.8p2");
+        assert_eq!(format_hexadecimal_float(&value, 1, true), "0X1.8P+2");
+    }
+
+    #[test]
+    fn test_format_hexadecimal_float_rounds_to_even() {
+        // 0x1.98p0 rounds up (tie, odd last kept bit) and 0x1.88p0 rounds
+        // down (tie, even last kept bit) when truncated to one hex digit.
+        assert_eq!(
+            format_hexadecimal_float(&parsed("0x1.98p0"), 1, false),
+            "0x1.ap+0"
+        );
+        assert_eq!(
+            format_hexadecimal_float(&parsed("0x1.88p0"), 1, false),
+            "0x1.8p+0"
+        );
+    }
+
+    #[test]
+    fn test_format_hexadecimal_float_rounding_carries_into_exponent() {
+        // All mantissa bits are 1, so rounding up rolls over to 0x1.0 and
+        // bumps the exponent.
+        assert_eq!(
+            format_hexadecimal_float(&parsed("0x1.f8p0"), 1, false),
+            "0x1.0p+1"
+        );
+    }
+
+    #[test]
+    fn test_format_hexadecimal_float_zero() {
+        use bigdecimal::BigDecimal;
+        use num_bigint::BigInt;
+
+        let zero = BigDecimal::from_bigint(BigInt::from(0), 0);
+        assert_eq!(
+            format_hexadecimal_float(&ExtendedBigDecimal::BigDecimal(zero), 2, false),
+            "0x0.00p+0"
+        );
+        assert_eq!(
+            format_hexadecimal_float(&ExtendedBigDecimal::MinusZero, 0, false),
+            "-0x0p+0"
+        );
+    }
+
+    #[test]
+    fn test_format_hexadecimal_float_non_finite() {
+        assert_eq!(
+            format_hexadecimal_float(&ExtendedBigDecimal::Infinity, 2, false),
+            "inf"
+        );
+        assert_eq!(
+            format_hexadecimal_float(&ExtendedBigDecimal::MinusInfinity, 2, false),
+            "-inf"
+        );
+        assert_eq!(
+            format_hexadecimal_float(&ExtendedBigDecimal::Nan, 2, true),
+            "NAN"
+        );
+        assert_eq!(
+            format_hexadecimal_float(&ExtendedBigDecimal::MinusNan, 2, true),
+            "-NAN"
+        );
+    }
 }